@@ -38,6 +38,30 @@ register_bitfields! {u64,
             Disable = 0b1
         ],
 
+        /// Traps execution at EL1 and EL0 of SME instructions, and accesses to SVCR, SMCR_EL1,
+        /// and the ZA and streaming SVE register state, to EL1, or to EL2 when EL2 is implemented
+        /// and enabled in the current Security state and HCR_EL2.TGE is 1.
+        ///
+        /// The exception is reported using ESR_ELx.EC value 0x1D.
+        ///
+        /// A trap taken as a result of CPACR_EL1.SMEN has precedence over a trap taken as a
+        /// result of CPACR_EL1.FPEN.
+        ///
+        /// 00 This control causes execution of these instructions at EL1 and EL0 to be trapped.
+        ///
+        /// 01 This control causes execution of these instructions at EL0 to be trapped, but does
+        ///    not cause execution of any instructions at EL1 to be trapped.
+        ///
+        /// 10 This control causes execution of these instructions at EL1 and EL0 to be trapped.
+        ///
+        /// 11 This control does not cause execution of any instructions to be trapped.
+        SMEN OFFSET(24) NUMBITS(2) [
+            Disable = 0b00,
+            EnableAtEL1 = 0b01,
+            Disable2 = 0b10,
+            Enable = 0b11
+        ],
+
         /// Traps execution at EL1 and EL0 of instructions that access the Advanced SIMD and
         /// floating-point registers from both Execution states to EL1, reported using ESR_ELx.EC
         /// value 0x07, or to EL2 reported using ESR_ELx.EC value 0x00 when EL2 is implemented and
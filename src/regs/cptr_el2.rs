@@ -0,0 +1,158 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2021 by the author(s)
+//
+// Author(s):
+//   - Hongqin Li <ihongqinli@gmail.com>
+
+//! Architectural Feature Trap Register - EL2
+//!
+//! Controls access to trace, Activity Monitor, SME, SVE, and Advanced SIMD and
+//! floating-point functionality from EL1 and EL0, and redirects the
+//! corresponding traps to EL2.
+//!
+//! The layout of this register depends on HCR_EL2.E2H. When E2H is 0 (or
+//! FEAT_VHE is not implemented), the `TTA`/`TFP`/`TZ` fields below apply.
+//! When E2H is 1, the register reuses the CPACR_EL1 two-bit encoding via
+//! `TTA_E2H`/`FPEN`/`ZEN`/`SMEN`, so a caller running with VHE enabled can
+//! program CPTR_EL2 identically to CPACR_EL1.
+
+use register::{cpu::RegisterReadWrite, register_bitfields};
+
+register_bitfields! {u64,
+    pub CPTR_EL2 [
+        /// Traps accesses to CPACR_EL1 from EL1 to EL2, unless the access generates a higher
+        /// priority exception. Valid regardless of HCR_EL2.E2H.
+        ///
+        /// 0 This control does not cause any instructions to be trapped.
+        ///
+        /// 1 This control causes accesses to CPACR_EL1 to be trapped to EL2.
+        TCPAC OFFSET(31) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps accesses to the Activity Monitor registers from EL1 and EL0 to EL2, when
+        /// FEAT_AMUv1 is implemented. Valid regardless of HCR_EL2.E2H.
+        ///
+        /// 0 This control does not cause any instructions to be trapped.
+        ///
+        /// 1 This control causes accesses to Activity Monitor registers to be trapped to EL2.
+        TAM OFFSET(30) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps execution at EL1 and EL0 of SME instructions, and accesses to SVCR, SMCR_EL1,
+        /// and the ZA and streaming SVE register state, to EL2, when FEAT_SME is implemented and
+        /// HCR_EL2.E2H is 1.
+        ///
+        /// 00 This control causes execution of these instructions at EL1 and EL0 to be trapped.
+        ///
+        /// 01 This control causes execution of these instructions at EL0 to be trapped, but does
+        ///    not cause execution of any instructions at EL1 to be trapped.
+        ///
+        /// 10 This control causes execution of these instructions at EL1 and EL0 to be trapped.
+        ///
+        /// 11 This control does not cause execution of any instructions to be trapped.
+        SMEN OFFSET(24) NUMBITS(2) [
+            Disable = 0b00,
+            EnableAtEL1 = 0b01,
+            Disable2 = 0b10,
+            Enable = 0b11
+        ],
+
+        /// When HCR_EL2.E2H is 1, traps System register accesses to all implemented trace
+        /// registers from EL1 and EL0 to EL2, using the same two-value encoding as
+        /// CPACR_EL1.TTA.
+        ///
+        /// 0 This control does not cause any instructions to be trapped.
+        ///
+        /// 1 This control causes accesses to trace registers to be trapped to EL2.
+        TTA_E2H OFFSET(28) NUMBITS(1) [
+            Enable = 0b0,
+            Disable = 0b1
+        ],
+
+        /// Traps execution at EL1 and EL0 of instructions that access the Advanced SIMD and
+        /// floating-point registers, including SVE instructions if implemented, to EL2, when
+        /// HCR_EL2.E2H is 1. Uses the same encoding as CPACR_EL1.FPEN.
+        ///
+        /// 00 This control causes execution of these instructions at EL1 and EL0 to be trapped.
+        ///
+        /// 01 This control causes execution of these instructions at EL0 to be trapped, but does
+        ///    not cause execution of any instructions at EL1 to be trapped.
+        ///
+        /// 10 This control causes execution of these instructions at EL1 and EL0 to be trapped.
+        ///
+        /// 11 This control does not cause execution of any instructions to be trapped.
+        FPEN OFFSET(20) NUMBITS(2) [
+            Disable = 0b00,
+            EnableAtEL1 = 0b01,
+            Disable2 = 0b10,
+            Enable = 0b11
+        ],
+
+        /// When HCR_EL2.E2H is 0, traps System register accesses to all implemented trace
+        /// registers from EL1 and EL0 to EL2.
+        ///
+        /// 0 This control does not cause any instructions to be trapped.
+        ///
+        /// 1 This control causes accesses to trace registers to be trapped to EL2.
+        TTA OFFSET(20) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps execution at EL1 and EL0 of SVE instructions, and accesses to ZCR_EL1, to EL2,
+        /// when FEAT_SVE is implemented and HCR_EL2.E2H is 1. Uses the same encoding as
+        /// CPACR_EL1.ZEN.
+        ///
+        /// 00 This control causes execution of these instructions at EL1 and EL0 to be trapped.
+        ///
+        /// 01 This control causes execution of these instructions at EL0 to be trapped, but does
+        ///    not cause execution of any instructions at EL1 to be trapped.
+        ///
+        /// 10 This control causes execution of these instructions at EL1 and EL0 to be trapped.
+        ///
+        /// 11 This control does not cause execution of any instructions to be trapped.
+        ZEN OFFSET(16) NUMBITS(2) [
+            Disable = 0b00,
+            EnableAtEL1 = 0b01,
+            Disable2 = 0b10,
+            Enable = 0b11
+        ],
+
+        /// When HCR_EL2.E2H is 0, traps execution at EL1 and EL0 of instructions that access the
+        /// Advanced SIMD and floating-point registers, including SVE instructions if implemented,
+        /// to EL2.
+        ///
+        /// 0 This control does not cause any instructions to be trapped.
+        ///
+        /// 1 This control causes execution of these instructions to be trapped to EL2.
+        TFP OFFSET(10) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// When HCR_EL2.E2H is 0, traps execution at EL1 and EL0 of SVE instructions, and
+        /// accesses to ZCR_EL1, to EL2, when FEAT_SVE is implemented.
+        ///
+        /// 0 This control does not cause any instructions to be trapped.
+        ///
+        /// 1 This control causes execution of these instructions to be trapped to EL2.
+        TZ OFFSET(8) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl RegisterReadWrite<u64, CPTR_EL2::Register> for Reg {
+    sys_coproc_read_raw!(u64, "CPTR_EL2", "x");
+    sys_coproc_write_raw!(u64, "CPTR_EL2", "x");
+}
+
+pub static CPTR_EL2: Reg = Reg {};
@@ -0,0 +1,93 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2021 by the author(s)
+//
+// Author(s):
+//   - Hongqin Li <ihongqinli@gmail.com>
+
+//! Architectural Feature Trap Register - EL3
+//!
+//! Controls access to trace, Activity Monitor, SME, SVE, and Advanced SIMD and
+//! floating-point functionality from EL2, EL1, and EL0, and redirects the
+//! corresponding traps to EL3.
+
+use register::{cpu::RegisterReadWrite, register_bitfields};
+
+register_bitfields! {u64,
+    pub CPTR_EL3 [
+        /// Traps accesses to CPTR_EL2 and, when EL2 is implemented, CPACR_EL1 from EL2 and EL1
+        /// to EL3, unless the access generates a higher priority exception.
+        ///
+        /// 0 This control does not cause any instructions to be trapped.
+        ///
+        /// 1 This control causes accesses to CPTR_EL2 and CPACR_EL1 to be trapped to EL3.
+        TCPAC OFFSET(31) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps accesses to the Activity Monitor registers from EL2, EL1, and EL0 to EL3, when
+        /// FEAT_AMUv1 is implemented.
+        ///
+        /// 0 This control does not cause any instructions to be trapped.
+        ///
+        /// 1 This control causes accesses to Activity Monitor registers to be trapped to EL3.
+        TAM OFFSET(30) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps System register accesses to all implemented trace registers from EL2, EL1, and
+        /// EL0 to EL3.
+        ///
+        /// 0 This control does not cause any instructions to be trapped.
+        ///
+        /// 1 This control causes accesses to trace registers to be trapped to EL3.
+        TTA OFFSET(20) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps execution of SME instructions, and accesses to SVCR, SMCR_EL2, and SMCR_EL1,
+        /// from EL2, EL1, and EL0 to EL3, when FEAT_SME is implemented.
+        ///
+        /// 0 This control does not cause any instructions to be trapped.
+        ///
+        /// 1 This control causes execution of these instructions to be trapped to EL3.
+        ESM OFFSET(12) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps execution at EL2, EL1, and EL0 of instructions that access the Advanced SIMD
+        /// and floating-point registers, including SVE instructions if implemented, to EL3.
+        ///
+        /// 0 This control does not cause any instructions to be trapped.
+        ///
+        /// 1 This control causes execution of these instructions to be trapped to EL3.
+        TFP OFFSET(10) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps execution at EL2, EL1, and EL0 of SVE instructions, and accesses to ZCR_EL2
+        /// and ZCR_EL1, to EL3, when FEAT_SVE is implemented.
+        ///
+        /// 0 This control does not cause any instructions to be trapped.
+        ///
+        /// 1 This control causes execution of these instructions to be trapped to EL3.
+        EZ OFFSET(8) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl RegisterReadWrite<u64, CPTR_EL3::Register> for Reg {
+    sys_coproc_read_raw!(u64, "CPTR_EL3", "x");
+    sys_coproc_write_raw!(u64, "CPTR_EL3", "x");
+}
+
+pub static CPTR_EL3: Reg = Reg {};
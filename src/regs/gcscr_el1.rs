@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2021 by the author(s)
+//
+// Author(s):
+//   - Hongqin Li <ihongqinli@gmail.com>
+
+//! Guarded Control Stack Control Register - EL1
+//!
+//! Controls Guarded Control Stack usage at EL1.
+
+use register::{cpu::RegisterReadWrite, register_bitfields};
+
+register_bitfields! {u64,
+    pub GCSCR_EL1 [
+        /// Trap GCSPR_EL1-relative loads and stores that are not permitted to the current GCS
+        /// mode.
+        PCRSEL OFFSET(0) NUMBITS(1) [
+            Disable = 0b0,
+            Enable = 0b1
+        ],
+
+        /// Return value checking enable. Enables consistency checks between the link register
+        /// and the value stored on the Guarded Control Stack on RET and RETAA/RETAB.
+        RVCHKEN OFFSET(5) NUMBITS(1) [
+            Disable = 0b0,
+            Enable = 0b1
+        ],
+
+        /// Exception return lock enable. When set, writes to ELR_EL1 and SPSR_EL1 are locked
+        /// except when performed through the Guarded Control Stack exception return mechanism.
+        EXLOCKEN OFFSET(6) NUMBITS(1) [
+            Disable = 0b0,
+            Enable = 0b1
+        ],
+
+        /// Traps execution of GCSPUSHM at EL1 to EL1, reported using ESR_ELx.EC value 0x2D.
+        PUSHMEn OFFSET(8) NUMBITS(1) [
+            Disable = 0b0,
+            Enable = 0b1
+        ],
+
+        /// Traps execution at EL1 of STR and STGM-style store-to-GCS instructions that are not
+        /// GCS push instructions.
+        STREn OFFSET(9) NUMBITS(1) [
+            Disable = 0b0,
+            Enable = 0b1
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl RegisterReadWrite<u64, GCSCR_EL1::Register> for Reg {
+    sys_coproc_read_raw!(u64, "GCSCR_EL1", "x");
+    sys_coproc_write_raw!(u64, "GCSCR_EL1", "x");
+}
+
+pub static GCSCR_EL1: Reg = Reg {};
@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2021 by the author(s)
+//
+// Author(s):
+//   - Hongqin Li <ihongqinli@gmail.com>
+
+//! Guarded Control Stack Control Register (EL0)
+//!
+//! Controls Guarded Control Stack usage at EL0, as seen from EL1.
+
+use register::{cpu::RegisterReadWrite, register_bitfields};
+
+register_bitfields! {u64,
+    pub GCSCRE0_EL1 [
+        /// Trap GCSPR_EL0-relative loads and stores that are not permitted to the current GCS
+        /// mode.
+        PCRSEL OFFSET(0) NUMBITS(1) [
+            Disable = 0b0,
+            Enable = 0b1
+        ],
+
+        /// Return value checking enable at EL0. Enables consistency checks between the link
+        /// register and the value stored on the Guarded Control Stack on RET and RETAA/RETAB
+        /// executed at EL0.
+        RVCHKEN OFFSET(5) NUMBITS(1) [
+            Disable = 0b0,
+            Enable = 0b1
+        ],
+
+        /// Traps execution of GCSPUSHM at EL0 to EL1, reported using ESR_ELx.EC value 0x2D.
+        PUSHMEn OFFSET(8) NUMBITS(1) [
+            Disable = 0b0,
+            Enable = 0b1
+        ],
+
+        /// Traps execution at EL0 of STR and STGM-style store-to-GCS instructions that are not
+        /// GCS push instructions.
+        STREn OFFSET(9) NUMBITS(1) [
+            Disable = 0b0,
+            Enable = 0b1
+        ],
+
+        /// Disables use of the Guarded Control Stack at EL0. When clear, GCS operations at EL0
+        /// are enabled; when set, GCS instructions executed at EL0 are UNDEFINED.
+        nTR OFFSET(10) NUMBITS(1) [
+            Enable = 0b0,
+            Disable = 0b1
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl RegisterReadWrite<u64, GCSCRE0_EL1::Register> for Reg {
+    sys_coproc_read_raw!(u64, "GCSCRE0_EL1", "x");
+    sys_coproc_write_raw!(u64, "GCSCRE0_EL1", "x");
+}
+
+pub static GCSCRE0_EL1: Reg = Reg {};
@@ -0,0 +1,22 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2021 by the author(s)
+//
+// Author(s):
+//   - Hongqin Li <ihongqinli@gmail.com>
+
+//! Guarded Control Stack Pointer Register - EL2
+//!
+//! Holds the current Guarded Control Stack pointer for EL2. Bits[2:0] are
+//! RES0, since GCS entries are doubleword-aligned.
+
+use register::cpu::RegisterReadWrite;
+
+pub struct Reg;
+
+impl RegisterReadWrite<u64, ()> for Reg {
+    sys_coproc_read_raw!(u64, "GCSPR_EL2", "x");
+    sys_coproc_write_raw!(u64, "GCSPR_EL2", "x");
+}
+
+pub static GCSPR_EL2: Reg = Reg {};
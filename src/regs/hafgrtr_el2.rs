@@ -0,0 +1,98 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2021 by the author(s)
+//
+// Author(s):
+//   - Hongqin Li <ihongqinli@gmail.com>
+
+//! Activity Monitor Fine-Grained Read Trap Register
+//!
+//! Part of FEAT_FGT, requires FEAT_AMUv1. Allows individual System register
+//! read accesses to Activity Monitor registers that would otherwise be
+//! executed at EL1 to be trapped to EL2. Trapping via this register is
+//! enabled by SCR_EL3.FGTEn.
+
+use register::{cpu::RegisterReadWrite, register_bitfields};
+
+register_bitfields! {u64,
+    pub HAFGRTR_EL2 [
+        /// Traps reads of AMEVCNTR0<n>_EL0, n = 0 - 3, to EL2.
+        AMEVCNTR00_EL0 OFFSET(0) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+        AMEVCNTR01_EL0 OFFSET(1) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+        AMEVCNTR02_EL0 OFFSET(2) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+        AMEVCNTR03_EL0 OFFSET(3) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps reads of AMEVCNTR1<n>_EL0, n = 0 - 5, to EL2. No fields are defined here for
+        /// n = 6 - 15.
+        AMEVCNTR10_EL0 OFFSET(16) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+        AMEVCNTR11_EL0 OFFSET(17) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+        AMEVCNTR12_EL0 OFFSET(18) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+        AMEVCNTR13_EL0 OFFSET(19) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+        AMEVCNTR14_EL0 OFFSET(20) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+        AMEVCNTR15_EL0 OFFSET(21) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps reads of AMEVTYPER1<n>_EL0, n = 0 - 1, to EL2. No fields are defined here for
+        /// n = 2 - 15.
+        AMEVTYPER10_EL0 OFFSET(32) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+        AMEVTYPER11_EL0 OFFSET(33) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps reads of AMCNTEN0, the group 0 counter enable bits in AMCNTENSET0_EL0 and
+        /// AMCNTENCLR0_EL0, to EL2.
+        AMCNTEN0 OFFSET(4) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps reads of AMCNTEN1, the group 1 counter enable bits in AMCNTENSET1_EL0 and
+        /// AMCNTENCLR1_EL0, to EL2.
+        AMCNTEN1 OFFSET(5) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl RegisterReadWrite<u64, HAFGRTR_EL2::Register> for Reg {
+    sys_coproc_read_raw!(u64, "HAFGRTR_EL2", "x");
+    sys_coproc_write_raw!(u64, "HAFGRTR_EL2", "x");
+}
+
+pub static HAFGRTR_EL2: Reg = Reg {};
@@ -0,0 +1,142 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2021 by the author(s)
+//
+// Author(s):
+//   - Hongqin Li <ihongqinli@gmail.com>
+
+//! Debug Fine-Grained Read Trap Register
+//!
+//! Part of FEAT_FGT. Allows individual System register read accesses to
+//! debug, trace, and PMU registers that would otherwise be executed at EL1
+//! to be trapped to EL2. Trapping via this register is enabled by
+//! SCR_EL3.FGTEn.
+
+use register::{cpu::RegisterReadWrite, register_bitfields};
+
+register_bitfields! {u64,
+    pub HDFGRTR_EL2 [
+        /// Traps reads of DBGBCRn_EL1 to EL2.
+        DBGBCRn_EL1 OFFSET(0) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps reads of DBGBVRn_EL1 to EL2.
+        DBGBVRn_EL1 OFFSET(1) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps reads of DBGWCRn_EL1 to EL2.
+        DBGWCRn_EL1 OFFSET(2) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps reads of DBGWVRn_EL1 to EL2.
+        DBGWVRn_EL1 OFFSET(3) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps reads of MDSCR_EL1 to EL2.
+        MDSCR_EL1 OFFSET(4) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps reads of OSLSR_EL1 to EL2.
+        OSLSR_EL1 OFFSET(23) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps direct reads of PMCCFILTR_EL0 to EL2.
+        PMCCFILTR_EL0 OFFSET(46) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps direct reads of PMCCNTR_EL0 to EL2.
+        PMCCNTR_EL0 OFFSET(41) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps direct reads of PMCEID0_EL0 and PMCEID1_EL0 to EL2.
+        PMCEIDn_EL0 OFFSET(43) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps direct reads of PMCNTENSET_EL0 and PMCNTENCLR_EL0 to EL2.
+        PMCNTEN OFFSET(40) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps direct reads of PMCR_EL0 to EL2.
+        PMCR_EL0 OFFSET(44) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps direct reads of PMEVCNTRn_EL0 to EL2.
+        PMEVCNTRn_EL0 OFFSET(48) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps direct reads of PMEVTYPERn_EL0 to EL2.
+        PMEVTYPERn_EL0 OFFSET(49) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps direct reads of PMSELR_EL0 to EL2.
+        PMSELR_EL0 OFFSET(39) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps reads of TRBLIMITR_EL1 to EL2.
+        TRBLIMITR_EL1 OFFSET(27) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps reads of TRBPTR_EL1 to EL2.
+        TRBPTR_EL1 OFFSET(28) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps reads of TRBSR_EL1 to EL2.
+        TRBSR_EL1 OFFSET(29) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps reads of TRBTRG_EL1 to EL2.
+        TRBTRG_EL1 OFFSET(30) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps reads of TRFCR_EL1 to EL2.
+        TRFCR_EL1 OFFSET(25) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl RegisterReadWrite<u64, HDFGRTR_EL2::Register> for Reg {
+    sys_coproc_read_raw!(u64, "HDFGRTR_EL2", "x");
+    sys_coproc_write_raw!(u64, "HDFGRTR_EL2", "x");
+}
+
+pub static HDFGRTR_EL2: Reg = Reg {};
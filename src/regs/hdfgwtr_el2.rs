@@ -0,0 +1,142 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2021 by the author(s)
+//
+// Author(s):
+//   - Hongqin Li <ihongqinli@gmail.com>
+
+//! Debug Fine-Grained Write Trap Register
+//!
+//! Part of FEAT_FGT. Allows individual System register write accesses to
+//! debug, trace, and PMU registers that would otherwise be executed at EL1
+//! to be trapped to EL2. Trapping via this register is enabled by
+//! SCR_EL3.FGTEn.
+
+use register::{cpu::RegisterReadWrite, register_bitfields};
+
+register_bitfields! {u64,
+    pub HDFGWTR_EL2 [
+        /// Traps writes to DBGBCRn_EL1 to EL2.
+        DBGBCRn_EL1 OFFSET(0) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps writes to DBGBVRn_EL1 to EL2.
+        DBGBVRn_EL1 OFFSET(1) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps writes to DBGWCRn_EL1 to EL2.
+        DBGWCRn_EL1 OFFSET(2) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps writes to DBGWVRn_EL1 to EL2.
+        DBGWVRn_EL1 OFFSET(3) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps writes to MDSCR_EL1 to EL2.
+        MDSCR_EL1 OFFSET(4) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps execution of OSLAR_EL1 writes to EL2.
+        OSLAR_EL1 OFFSET(22) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps writes to PMCCFILTR_EL0 to EL2.
+        PMCCFILTR_EL0 OFFSET(46) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps writes to PMCCNTR_EL0 to EL2.
+        PMCCNTR_EL0 OFFSET(41) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps writes to PMCNTENSET_EL0 and PMCNTENCLR_EL0 to EL2.
+        PMCNTEN OFFSET(40) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps writes to PMCR_EL0 to EL2.
+        PMCR_EL0 OFFSET(44) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps writes to PMEVCNTRn_EL0 to EL2.
+        PMEVCNTRn_EL0 OFFSET(48) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps writes to PMEVTYPERn_EL0 to EL2.
+        PMEVTYPERn_EL0 OFFSET(49) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps writes to PMSELR_EL0 to EL2.
+        PMSELR_EL0 OFFSET(39) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps writes to PMSWINC_EL0 to EL2.
+        PMSWINC_EL0 OFFSET(38) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps writes to TRBLIMITR_EL1 to EL2.
+        TRBLIMITR_EL1 OFFSET(27) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps writes to TRBPTR_EL1 to EL2.
+        TRBPTR_EL1 OFFSET(28) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps writes to TRBSR_EL1 to EL2.
+        TRBSR_EL1 OFFSET(29) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps writes to TRBTRG_EL1 to EL2.
+        TRBTRG_EL1 OFFSET(30) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps writes to TRFCR_EL1 to EL2.
+        TRFCR_EL1 OFFSET(25) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl RegisterReadWrite<u64, HDFGWTR_EL2::Register> for Reg {
+    sys_coproc_read_raw!(u64, "HDFGWTR_EL2", "x");
+    sys_coproc_write_raw!(u64, "HDFGWTR_EL2", "x");
+}
+
+pub static HDFGWTR_EL2: Reg = Reg {};
@@ -0,0 +1,172 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2021 by the author(s)
+//
+// Author(s):
+//   - Hongqin Li <ihongqinli@gmail.com>
+
+//! Fine-Grained Instruction Trap Register
+//!
+//! Part of FEAT_FGT. Allows individual instructions that would otherwise be
+//! executed at EL1 or EL0 to be trapped to EL2, instead of using the
+//! coarse-grained traps provided by HCR_EL2. Trapping via this register is
+//! enabled by SCR_EL3.FGTEn.
+
+use register::{cpu::RegisterReadWrite, register_bitfields};
+
+register_bitfields! {u64,
+    pub HFGITR_EL2 [
+        /// Traps execution of AT S1E0R, AT S1E0W to EL2.
+        ATS1E0R OFFSET(15) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps execution of AT S1E1R, AT S1E1W to EL2.
+        ATS1E1R OFFSET(13) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps execution of AT S1E1RP, AT S1E1WP to EL2.
+        ATS1E1RP OFFSET(17) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps execution of DC CVAC to EL2.
+        DCCVAC OFFSET(10) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps execution of DC CVAU to EL2.
+        DCCVAU OFFSET(7) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps execution of DC CIVAC to EL2.
+        DCCIVAC OFFSET(11) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps execution of DC IVAC to EL2.
+        DCIVAC OFFSET(3) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps execution of DC CSW to EL2.
+        DCCSW OFFSET(5) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps execution of DC CISW to EL2.
+        DCCISW OFFSET(6) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps execution of DC ISW to EL2.
+        DCISW OFFSET(4) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps execution of DC ZVA to EL2.
+        DCZVA OFFSET(12) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps execution of ERET and ERETAA/ERETAB to EL2.
+        ERET OFFSET(39) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps execution of IC IALLU to EL2.
+        ICIALLU OFFSET(1) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps execution of IC IALLUIS to EL2.
+        ICIALLUIS OFFSET(0) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps execution of IC IVAU to EL2.
+        ICIVAU OFFSET(2) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps execution of SVC at EL1 to EL2.
+        SVC_EL1 OFFSET(56) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps execution of SVC at EL0 to EL2.
+        SVC_EL0 OFFSET(57) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps execution of TLBI VMALLE1 to EL2. Does not trap the IS variant, which is a
+        /// separate bit.
+        TLBIVMALLE1 OFFSET(45) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps execution of TLBI VAE1 to EL2. Does not trap the IS variant, which is a
+        /// separate bit.
+        TLBIVAE1 OFFSET(34) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps execution of TLBI ASIDE1 to EL2. Does not trap the IS variant, which is a
+        /// separate bit.
+        TLBIASIDE1 OFFSET(33) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps execution of TLBI VAAE1 to EL2. Does not trap the IS variant, which is a
+        /// separate bit.
+        TLBIVAAE1 OFFSET(32) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps execution of TLBI VALE1 to EL2. Does not trap the IS variant, which is a
+        /// separate bit.
+        TLBIVALE1 OFFSET(37) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps execution of TLBI VAALE1 to EL2. Does not trap the IS variant, which is a
+        /// separate bit.
+        TLBIVAALE1 OFFSET(36) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl RegisterReadWrite<u64, HFGITR_EL2::Register> for Reg {
+    sys_coproc_read_raw!(u64, "HFGITR_EL2", "x");
+    sys_coproc_write_raw!(u64, "HFGITR_EL2", "x");
+}
+
+pub static HFGITR_EL2: Reg = Reg {};
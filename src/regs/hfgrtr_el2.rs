@@ -0,0 +1,154 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2021 by the author(s)
+//
+// Author(s):
+//   - Hongqin Li <ihongqinli@gmail.com>
+
+//! Fine-Grained Read Trap Register
+//!
+//! Part of FEAT_FGT. Allows individual System register read accesses that
+//! would otherwise be executed at EL1 to be trapped to EL2, instead of using
+//! the coarse-grained traps provided by HCR_EL2 and CPTR_EL2. Trapping via
+//! this register is enabled by SCR_EL3.FGTEn.
+
+use register::{cpu::RegisterReadWrite, register_bitfields};
+
+register_bitfields! {u64,
+    pub HFGRTR_EL2 [
+        /// Traps reads of AFSR0_EL1 to EL2.
+        AFSR0_EL1 OFFSET(0) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps reads of AFSR1_EL1 to EL2.
+        AFSR1_EL1 OFFSET(1) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps reads of AMAIR_EL1 to EL2.
+        AMAIR_EL1 OFFSET(2) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps reads of the APDAKey registers (APDAKeyHi_EL1, APDAKeyLo_EL1) to EL2.
+        APDAKey OFFSET(3) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps reads of the APDBKey registers to EL2.
+        APDBKey OFFSET(4) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps reads of the APGAKey registers to EL2.
+        APGAKey OFFSET(5) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps reads of the APIAKey registers to EL2.
+        APIAKey OFFSET(6) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps reads of the APIBKey registers to EL2.
+        APIBKey OFFSET(7) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps reads of CCSIDR_EL1, CCSIDR2_EL1, CLIDR_EL1, and CSSELR_EL1 to EL2.
+        CSSELR_EL1 OFFSET(12) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps reads of CONTEXTIDR_EL1 to EL2.
+        CONTEXTIDR_EL1 OFFSET(10) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps reads of CPACR_EL1 to EL2, when this trap is not already applied by CPTR_EL2.
+        CPACR_EL1 OFFSET(11) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps reads of ESR_EL1 to EL2.
+        ESR_EL1 OFFSET(15) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps reads of FAR_EL1 to EL2.
+        FAR_EL1 OFFSET(16) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps reads of MAIR_EL1 to EL2.
+        MAIR_EL1 OFFSET(23) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps reads of PAR_EL1 to EL2.
+        PAR_EL1 OFFSET(26) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps reads of SCTLR_EL1 to EL2.
+        SCTLR_EL1 OFFSET(28) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps reads of TCR_EL1 to EL2.
+        TCR_EL1 OFFSET(31) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps reads of TPIDR_EL1 to EL2.
+        TPIDR_EL1 OFFSET(34) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps reads of TTBR0_EL1 to EL2.
+        TTBR0_EL1 OFFSET(35) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps reads of TTBR1_EL1 to EL2.
+        TTBR1_EL1 OFFSET(36) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps reads of VBAR_EL1 to EL2.
+        VBAR_EL1 OFFSET(37) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl RegisterReadWrite<u64, HFGRTR_EL2::Register> for Reg {
+    sys_coproc_read_raw!(u64, "HFGRTR_EL2", "x");
+    sys_coproc_write_raw!(u64, "HFGRTR_EL2", "x");
+}
+
+pub static HFGRTR_EL2: Reg = Reg {};
@@ -0,0 +1,160 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2021 by the author(s)
+//
+// Author(s):
+//   - Hongqin Li <ihongqinli@gmail.com>
+
+//! Secure Configuration Register - EL3
+//!
+//! Defines the Security state and execution state for lower Exception
+//! levels, and configures which features and traps are enabled at EL3.
+
+use register::{cpu::RegisterReadWrite, register_bitfields};
+
+register_bitfields! {u64,
+    pub SCR_EL3 [
+        /// Non-secure bit. Selects the Security state of EL1 and EL0, and, when EL2 is
+        /// implemented, EL2, as Secure or Non-secure.
+        NS OFFSET(0) NUMBITS(1) [
+            Secure = 0b0,
+            NonSecure = 0b1
+        ],
+
+        /// Physical IRQ Routing. When set, physical IRQ exceptions are taken in EL3, unless
+        /// they are routed to EL2.
+        IRQ OFFSET(1) NUMBITS(1) [
+            LowerEL = 0b0,
+            EL3 = 0b1
+        ],
+
+        /// Physical FIQ Routing. When set, physical FIQ exceptions are taken in EL3.
+        FIQ OFFSET(2) NUMBITS(1) [
+            LowerEL = 0b0,
+            EL3 = 0b1
+        ],
+
+        /// External Abort and SError interrupt Routing. When set, External aborts and SError
+        /// interrupts are taken in EL3, unless they are routed to EL2.
+        EA OFFSET(3) NUMBITS(1) [
+            LowerEL = 0b0,
+            EL3 = 0b1
+        ],
+
+        /// Secure Monitor Call disable. Disables SMC instructions at EL1 and above, when NS is
+        /// 1.
+        SMD OFFSET(7) NUMBITS(1) [
+            Enable = 0b0,
+            Disable = 0b1
+        ],
+
+        /// Hypervisor Call enable. Enables HVC instructions at EL1 and above.
+        HCE OFFSET(8) NUMBITS(1) [
+            Disable = 0b0,
+            Enable = 0b1
+        ],
+
+        /// Register width control for lower Exception levels. Selects whether the next lower
+        /// Exception level is AArch64 or AArch32.
+        RW OFFSET(10) NUMBITS(1) [
+            AArch32 = 0b0,
+            AArch64 = 0b1
+        ],
+
+        /// Traps Secure EL1 accesses to the Secure Timer registers to EL3.
+        ST OFFSET(11) NUMBITS(1) [
+            Trap = 0b0,
+            NoTrap = 0b1
+        ],
+
+        /// Traps WFI instructions at lower Exception levels to EL3.
+        TWI OFFSET(12) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Traps WFE instructions at lower Exception levels to EL3.
+        TWE OFFSET(13) NUMBITS(1) [
+            NoTrap = 0b0,
+            Trap = 0b1
+        ],
+
+        /// Controls the use of the APDAKey by lower Exception levels and, combined with APIAKey,
+        /// the availability of Pointer Authentication functionality in QARMA-based
+        /// implementations.
+        APK OFFSET(16) NUMBITS(1) [
+            Trap = 0b0,
+            NoTrap = 0b1
+        ],
+
+        /// Traps Pointer Authentication instructions and accesses to Pointer Authentication key
+        /// registers at lower Exception levels to EL3.
+        API OFFSET(17) NUMBITS(1) [
+            Trap = 0b0,
+            NoTrap = 0b1
+        ],
+
+        /// Enables EL2 in the current Security state when EL2 would not otherwise be
+        /// implemented in that Security state (FEAT_SEL2).
+        EEL2 OFFSET(18) NUMBITS(1) [
+            Disable = 0b0,
+            Enable = 0b1
+        ],
+
+        /// Fault Injection enable. Enables access to the RASv1p1 fault injection registers at
+        /// lower Exception levels.
+        FIEN OFFSET(21) NUMBITS(1) [
+            Disable = 0b0,
+            Enable = 0b1
+        ],
+
+        /// Non-maskable External Abort enable.
+        NMEA OFFSET(22) NUMBITS(1) [
+            Disable = 0b0,
+            Enable = 0b1
+        ],
+
+        /// Enables access to the SCXTNUM_EL1 and SCXTNUM_EL0 registers at lower Exception
+        /// levels without trapping to EL3 (FEAT_CSV2_2).
+        EnSCXT OFFSET(25) NUMBITS(1) [
+            Disable = 0b0,
+            Enable = 0b1
+        ],
+
+        /// Allocation Tag Access. Controls access to Allocation Tags and enables MTE
+        /// functionality at EL2, EL1, and EL0 (FEAT_MTE2).
+        ATA OFFSET(26) NUMBITS(1) [
+            Disable = 0b0,
+            Enable = 0b1
+        ],
+
+        /// Fine-Grained Traps Enable. Enables the FEAT_FGT fine-grained trap controls at EL2.
+        FGTEn OFFSET(27) NUMBITS(1) [
+            Disable = 0b0,
+            Enable = 0b1
+        ],
+
+        /// Enhanced Counter Virtualization enable. Enables the FEAT_ECV enhanced counter
+        /// virtualization extensions.
+        ECVEn OFFSET(28) NUMBITS(1) [
+            Disable = 0b0,
+            Enable = 0b1
+        ],
+
+        /// Non-secure State Enable. When set, enables use of the Realm and Root security states
+        /// (FEAT_RME).
+        NSE OFFSET(62) NUMBITS(1) [
+            Disable = 0b0,
+            Enable = 0b1
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl RegisterReadWrite<u64, SCR_EL3::Register> for Reg {
+    sys_coproc_read_raw!(u64, "SCR_EL3", "x");
+    sys_coproc_write_raw!(u64, "SCR_EL3", "x");
+}
+
+pub static SCR_EL3: Reg = Reg {};
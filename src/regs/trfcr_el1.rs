@@ -0,0 +1,65 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2021 by the author(s)
+//
+// Author(s):
+//   - Hongqin Li <ihongqinli@gmail.com>
+
+//! Trace Filter Control Register - EL1
+//!
+//! Controls self-hosted trace filtering, when FEAT_TRF is implemented. This
+//! register allows an OS to filter tracing by the Exception level and
+//! Security state that generated the trace.
+
+use register::{cpu::RegisterReadWrite, register_bitfields};
+
+register_bitfields! {u64,
+    pub TRFCR_EL1 [
+        /// Execution state timestamp control. Selects the value that is used by the trace unit
+        /// as the timestamp when TS in the trace unit's TRCCONFIGR is 0b0001.
+        ///
+        /// 00 Reserved.
+        ///
+        /// 01 Virtual timestamp. The physical counter value minus CNTVOFF_EL2.
+        ///
+        /// 10 Guest physical timestamp, when FEAT_ECV is implemented. The physical counter value
+        ///    minus CNTPOFF_EL2.
+        ///
+        /// 11 Physical timestamp.
+        TS OFFSET(5) NUMBITS(2) [
+            Reserved = 0b00,
+            Virtual = 0b01,
+            GuestPhysical = 0b10,
+            Physical = 0b11
+        ],
+
+        /// Enables tracing of CONTEXTIDR_EL1 value changes.
+        CX OFFSET(3) NUMBITS(1) [
+            Disable = 0b0,
+            Enable = 0b1
+        ],
+
+        /// Enables trace unit System register tracing in EL1 and, in Non-secure state when
+        /// EL2 is not implemented or not enabled, the corresponding EL0.
+        E1TRE OFFSET(1) NUMBITS(1) [
+            Disable = 0b0,
+            Enable = 0b1
+        ],
+
+        /// Enables trace unit System register tracing in EL0, when executing at EL0 with
+        /// TRFCR_EL1.E1TRE controlling the associated EL1.
+        E0TRE OFFSET(0) NUMBITS(1) [
+            Disable = 0b0,
+            Enable = 0b1
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl RegisterReadWrite<u64, TRFCR_EL1::Register> for Reg {
+    sys_coproc_read_raw!(u64, "TRFCR_EL1", "x");
+    sys_coproc_write_raw!(u64, "TRFCR_EL1", "x");
+}
+
+pub static TRFCR_EL1: Reg = Reg {};
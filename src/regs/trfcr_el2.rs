@@ -0,0 +1,64 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2021 by the author(s)
+//
+// Author(s):
+//   - Hongqin Li <ihongqinli@gmail.com>
+
+//! Trace Filter Control Register - EL2
+//!
+//! Controls self-hosted trace filtering, when FEAT_TRF is implemented. This
+//! register allows a hypervisor to filter tracing by the Exception level and
+//! Security state that generated the trace.
+
+use register::{cpu::RegisterReadWrite, register_bitfields};
+
+register_bitfields! {u64,
+    pub TRFCR_EL2 [
+        /// Execution state timestamp control. Selects the value that is used by the trace unit
+        /// as the timestamp when TS in the trace unit's TRCCONFIGR is 0b0001.
+        ///
+        /// 00 Controlled by TRFCR_EL1.TS, if accessible, otherwise behaves as 0b11.
+        ///
+        /// 01 Virtual timestamp. The physical counter value minus CNTVOFF_EL2.
+        ///
+        /// 10 Guest physical timestamp, when FEAT_ECV is implemented. The physical counter value
+        ///    minus CNTPOFF_EL2.
+        ///
+        /// 11 Physical timestamp.
+        TS OFFSET(5) NUMBITS(2) [
+            SpecifiedByTRFCR_EL1 = 0b00,
+            Virtual = 0b01,
+            GuestPhysical = 0b10,
+            Physical = 0b11
+        ],
+
+        /// Enables tracing of CONTEXTIDR_EL2 and VMID value changes.
+        CX OFFSET(3) NUMBITS(1) [
+            Disable = 0b0,
+            Enable = 0b1
+        ],
+
+        /// Enables trace unit System register tracing in EL2.
+        E2TRE OFFSET(1) NUMBITS(1) [
+            Disable = 0b0,
+            Enable = 0b1
+        ],
+
+        /// Enables trace unit System register tracing in EL0, when HCR_EL2.TGE is 1 and
+        /// HCR_EL2.E2H is 1 (that is, for the EL0 associated with EL2).
+        E0HTRE OFFSET(0) NUMBITS(1) [
+            Disable = 0b0,
+            Enable = 0b1
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl RegisterReadWrite<u64, TRFCR_EL2::Register> for Reg {
+    sys_coproc_read_raw!(u64, "TRFCR_EL2", "x");
+    sys_coproc_write_raw!(u64, "TRFCR_EL2", "x");
+}
+
+pub static TRFCR_EL2: Reg = Reg {};